@@ -1,6 +1,7 @@
 use meltdown::{
-    utils::{CatchPanicService, TaggedService},
-    Meltdown, Service, Token,
+    catch_panic::{CatchPanic, CatchPanicLayer},
+    tagged::{Tagged, TaggedLayer},
+    Meltdown, Service, ServiceExt, Token,
 };
 use std::time::Duration;
 
@@ -17,30 +18,26 @@ async fn sleep_panic(_token: Token) {
     panic!("something broke!");
 }
 
-fn service<S>(name: &'static str, service: S) -> CatchPanicService<TaggedService<S>>
+fn service<S>(name: &'static str, service: S) -> CatchPanic<Tagged<&'static str, S>>
 where
     S: Service,
 {
-    // service
-    //     .layer(TaggedLayer::new(name))
-    //     .layer(CatchPanicLayer::new())
-    //     .layer(RestartLayer::new())
-
-    CatchPanicService::new(TaggedService::new(name, service))
+    service
+        .layer(TaggedLayer::new(name))
+        .layer(CatchPanicLayer::new())
 }
 
 #[tokio::main]
 async fn main() {
-    let mut meltdown = Meltdown::new();
-
-    meltdown.register(service("sleep-1", long_sleep));
-    meltdown.register(service("sleep-2", short_sleep));
-    meltdown.register(service("sleep-3", short_sleep));
-    meltdown.register(service("sleep-4", long_sleep));
-    meltdown.register(service("sleep-5", sleep_panic));
-    meltdown.register(service("sleep-6", short_sleep));
-
-    while let Some(result) = meltdown.wait_next().await {
+    let mut meltdown = Meltdown::new()
+        .register(service("sleep-1", long_sleep))
+        .register(service("sleep-2", short_sleep))
+        .register(service("sleep-3", short_sleep))
+        .register(service("sleep-4", long_sleep))
+        .register(service("sleep-5", sleep_panic))
+        .register(service("sleep-6", short_sleep));
+
+    while let Some(result) = meltdown.next().await {
         println!("{result:?}");
     }
 }
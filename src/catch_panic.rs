@@ -25,7 +25,7 @@
 //! # })
 //! ```
 
-use crate::Service;
+use crate::{Layer, Service};
 use alloc::boxed::Box;
 use core::{
     any::Any,
@@ -69,6 +69,28 @@ impl<S: Service> Service for CatchPanic<S> {
     }
 }
 
+/// A [`Layer`] that wraps a service with [`CatchPanic`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CatchPanicLayer {
+    _private: (),
+}
+
+impl CatchPanicLayer {
+    /// Creates a new panic catching layer.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl<S> Layer<S> for CatchPanicLayer {
+    type Service = CatchPanic<S>;
+
+    fn layer(self, inner: S) -> Self::Service {
+        CatchPanic::new(inner)
+    }
+}
+
 impl<F: Future> Future for CatchPanicFuture<F> {
     type Output = Result<F::Output, Box<dyn Any + Send>>;
 
@@ -84,7 +106,7 @@ impl<F: Future> Future for CatchPanicFuture<F> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Token;
+    use crate::{ServiceExt, Token};
 
     async fn panic_service(_token: Token) {
         panic!();
@@ -97,4 +119,12 @@ mod tests {
             assert!(service.run(Token::new()).await.is_err());
         });
     }
+
+    #[test]
+    fn catch_panic_layer_wraps_a_service() {
+        pollster::block_on(async {
+            let service = panic_service.layer(CatchPanicLayer::new());
+            assert!(service.run(Token::new()).await.is_err());
+        });
+    }
 }
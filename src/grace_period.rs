@@ -0,0 +1,177 @@
+//! Service for bounding how long a service is given to shut down.
+//!
+//! # Examples
+//!
+//! ```
+//! # pollster::block_on(async {
+//! use core::time::Duration;
+//! use meltdown::{grace_period::GracePeriod, Meltdown};
+//!
+//! let mut meltdown = Meltdown::new().register(GracePeriod::new(
+//!     |token: meltdown::Token| async move {
+//!         token.await;
+//!         // Never resolves, so the grace period always elapses first.
+//!         core::future::pending::<()>().await;
+//!     },
+//!     Duration::from_millis(10),
+//!     |delay| async move {
+//!         // Stand in for a runtime-provided sleep.
+//!         let _ = delay;
+//!     },
+//! ));
+//!
+//! meltdown.trigger();
+//! assert!(meltdown.next().await.unwrap().is_err());
+//! # })
+//! ```
+
+use crate::{Service, Token};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use pin_project_lite::pin_project;
+
+/// The service didn't resolve before its grace period elapsed and was dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut;
+
+/// Bounds how long the inner service is given to shut down once its token is triggered.
+#[derive(Debug, Clone, Copy)]
+pub struct GracePeriod<S, D> {
+    inner: S,
+    grace_period: Duration,
+    delay: D,
+}
+
+impl<S, D> GracePeriod<S, D> {
+    /// Wraps `inner` with a grace period.
+    ///
+    /// The grace period is only armed once the service's token is triggered; if `inner` hasn't
+    /// resolved by `grace_period` after that, it is dropped and [`TimedOut`] is yielded instead.
+    /// `delay` turns a [`Duration`] into a future that resolves once that much time has passed,
+    /// so the crate stays runtime-agnostic.
+    pub const fn new(inner: S, grace_period: Duration, delay: D) -> Self {
+        Self {
+            inner,
+            grace_period,
+            delay,
+        }
+    }
+}
+
+impl<S, D, Fut> Service for GracePeriod<S, D>
+where
+    S: Service,
+    D: Fn(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Future = GracePeriodFuture<S::Future, D, Fut>;
+
+    fn run(self, token: Token) -> Self::Future {
+        let Self {
+            inner,
+            grace_period,
+            delay,
+        } = self;
+
+        GracePeriodFuture {
+            inner: inner.run(token.clone()),
+            grace_period,
+            delay,
+            timer: Timer::Waiting { token },
+        }
+    }
+}
+
+pin_project! {
+    #[project = TimerProj]
+    enum Timer<DelayFut> {
+        Waiting { token: Token },
+        Armed { #[pin] future: DelayFut },
+    }
+}
+
+pin_project! {
+    /// Future for the [`GracePeriod`] service.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct GracePeriodFuture<S, D, DelayFut> {
+        #[pin]
+        inner: S,
+        grace_period: Duration,
+        delay: D,
+        #[pin]
+        timer: Timer<DelayFut>,
+    }
+}
+
+impl<S, D, Fut> Future for GracePeriodFuture<S, D, Fut>
+where
+    S: Future,
+    D: Fn(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Output = Result<S::Output, TimedOut>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if let Poll::Ready(output) = this.inner.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        loop {
+            match this.timer.as_mut().project() {
+                TimerProj::Waiting { token } => match Pin::new(token).poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => {
+                        let future = (this.delay)(*this.grace_period);
+                        this.timer.set(Timer::Armed { future });
+                    }
+                },
+                TimerProj::Armed { future } => {
+                    return future.poll(cx).map(|()| Err(TimedOut));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_normally_if_inner_finishes_in_time() {
+        pollster::block_on(async {
+            let service = GracePeriod::new(
+                |_token| async { "done" },
+                Duration::from_secs(1),
+                |_delay| core::future::pending(),
+            );
+
+            assert_eq!(service.run(Token::new()).await, Ok("done"));
+        });
+    }
+
+    #[test]
+    fn times_out_if_inner_never_finishes() {
+        pollster::block_on(async {
+            let service = GracePeriod::new(
+                |token: Token| async move {
+                    token.await;
+                    core::future::pending::<()>().await;
+                },
+                Duration::from_secs(1),
+                |_delay| async {},
+            );
+
+            let token = Token::new();
+            token.trigger();
+
+            assert_eq!(service.run(token).await, Err(TimedOut));
+        });
+    }
+}
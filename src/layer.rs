@@ -0,0 +1,178 @@
+//! Composable middleware for decorating services with additional behaviour.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(all(feature = "tagged", feature = "catch-panic"))]
+//! # {
+//! use meltdown::{catch_panic::CatchPanicLayer, tagged::TaggedLayer, ServiceExt, Token};
+//!
+//! let service = (|token: Token| async move {
+//!     token.await;
+//! })
+//! .layer(TaggedLayer::new("db"))
+//! .layer(CatchPanicLayer::new());
+//! # let _ = service;
+//! # }
+//! ```
+
+use crate::Service;
+
+/// Wraps a [`Service`] to decorate it with additional behaviour.
+///
+/// Layers are usually applied with [`ServiceExt::layer`], or stacked ahead of time with a
+/// [`ServiceBuilder`] and applied to a base service later.
+pub trait Layer<S> {
+    /// The wrapped service produced by this layer.
+    type Service;
+
+    /// Wraps `inner` with this layer.
+    fn layer(self, inner: S) -> Self::Service;
+}
+
+/// Extension trait for composing [`Service`]s with [`Layer`]s.
+pub trait ServiceExt: Service + Sized {
+    /// Wraps this service with `layer`.
+    ///
+    /// Layers stack outermost-last: the first call to `.layer()` wraps closest to the base
+    /// service, and each subsequent call wraps around the previous result.
+    fn layer<L>(self, layer: L) -> L::Service
+    where
+        L: Layer<Self>,
+    {
+        layer.layer(self)
+    }
+}
+
+impl<S: Service> ServiceExt for S {}
+
+/// Builds a stack of [`Layer`]s to apply to a base [`Service`] later.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "tagged", feature = "catch-panic"))]
+/// # {
+/// use meltdown::{catch_panic::CatchPanicLayer, tagged::TaggedLayer, ServiceBuilder, Token};
+///
+/// let service = ServiceBuilder::new()
+///     .layer(TaggedLayer::new("db"))
+///     .layer(CatchPanicLayer::new())
+///     .service(|token: Token| async move {
+///         token.await;
+///     });
+/// # let _ = service;
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceBuilder<L> {
+    layer: L,
+}
+
+impl ServiceBuilder<Identity> {
+    /// Creates a new, empty service builder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            layer: Identity::new(),
+        }
+    }
+}
+
+impl<L> ServiceBuilder<L> {
+    /// Adds a layer to the stack.
+    ///
+    /// The new layer wraps around everything added so far, and ends up closer to the base
+    /// service than any layer added after it.
+    #[must_use]
+    pub fn layer<T>(self, layer: T) -> ServiceBuilder<Stack<L, T>> {
+        ServiceBuilder {
+            layer: Stack::new(self.layer, layer),
+        }
+    }
+
+    /// Applies the layer stack to `service`, producing the final wrapped service.
+    pub fn service<S>(self, service: S) -> L::Service
+    where
+        L: Layer<S>,
+    {
+        self.layer.layer(service)
+    }
+}
+
+impl Default for ServiceBuilder<Identity> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`Layer`] that applies an inner layer followed by an outer layer.
+#[derive(Debug, Clone, Copy)]
+pub struct Stack<Inner, Outer> {
+    inner: Inner,
+    outer: Outer,
+}
+
+impl<Inner, Outer> Stack<Inner, Outer> {
+    const fn new(inner: Inner, outer: Outer) -> Self {
+        Self { inner, outer }
+    }
+}
+
+impl<S, Inner, Outer> Layer<S> for Stack<Inner, Outer>
+where
+    Inner: Layer<S>,
+    Outer: Layer<Inner::Service>,
+{
+    type Service = Outer::Service;
+
+    fn layer(self, inner: S) -> Self::Service {
+        self.outer.layer(self.inner.layer(inner))
+    }
+}
+
+/// A [`Layer`] that returns the service unchanged.
+#[derive(Debug, Clone, Copy)]
+pub struct Identity {
+    _private: (),
+}
+
+impl Identity {
+    const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl<S> Layer<S> for Identity {
+    type Service = S;
+
+    fn layer(self, inner: S) -> Self::Service {
+        inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Token;
+
+    #[test]
+    fn identity_layer_returns_service_unchanged() {
+        pollster::block_on(async {
+            let service = (|_token: Token| async { "service" }).layer(Identity::new());
+            assert_eq!(service.run(Token::new()).await, "service");
+        });
+    }
+
+    #[test]
+    fn service_builder_stacks_layers_around_a_base_service() {
+        pollster::block_on(async {
+            let service = ServiceBuilder::new()
+                .layer(Identity::new())
+                .layer(Identity::new())
+                .service(|_token: Token| async { "service" });
+
+            assert_eq!(service.run(Token::new()).await, "service");
+        });
+    }
+}
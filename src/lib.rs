@@ -28,6 +28,13 @@
 //!
 //! For more complex services, you can implement the [`Service`] trait directly.
 //!
+//! # Composing Services
+//!
+//! Services can be decorated with [`Layer`]s, which mirrors the middleware-stack model used by
+//! crates like `tower`. Use [`ServiceExt::layer`] to wrap a service directly, or a
+//! [`ServiceBuilder`] to assemble a stack of layers ahead of time and apply it to a base service
+//! later.
+//!
 //! # Managing Services
 //!
 //! Use [`Meltdown`] to register and manage your services:
@@ -57,18 +64,40 @@
 //! }
 //! # })
 //! ```
+//!
+//! All services are triggered together by a single, global [`Token`]. When services need to
+//! shut down in a particular order instead, use [`PhasedMeltdown`] to register them into named
+//! phases that are triggered one after another.
+//!
+//! [`Meltdown`] requires each service's future to be [`Send`], since it boxes them into a `Send`
+//! future set. For services whose futures aren't, such as ones holding `Rc` or other thread-local
+//! state, use
+//! [`LocalMeltdown`] instead, and drive it on a single thread.
 
 extern crate alloc;
 
 #[cfg(feature = "catch-panic")]
 pub mod catch_panic;
+#[cfg(feature = "grace-period")]
+pub mod grace_period;
+#[cfg(feature = "restart")]
+pub mod restart;
 #[cfg(feature = "tagged")]
 pub mod tagged;
 
+mod layer;
+mod local;
+mod phased;
 mod service;
 mod token;
 
-pub use self::{service::Service, token::Token};
+pub use self::{
+    layer::{Layer, ServiceBuilder, ServiceExt},
+    local::LocalMeltdown,
+    phased::PhasedMeltdown,
+    service::Service,
+    token::Token,
+};
 
 use alloc::boxed::Box;
 use core::{future::Future, pin::Pin};
@@ -0,0 +1,142 @@
+//! A single-threaded variant of [`Meltdown`] for `!Send` services.
+//!
+//! # Examples
+//!
+//! ```
+//! # pollster::block_on(async {
+//! use meltdown::LocalMeltdown;
+//! use std::rc::Rc;
+//!
+//! let state = Rc::new("shared, thread-local state");
+//!
+//! let mut meltdown = LocalMeltdown::new().register(move |_| {
+//!     let state = Rc::clone(&state);
+//!     async move { *state }
+//! });
+//!
+//! assert_eq!(meltdown.next().await, Some("shared, thread-local state"));
+//! # })
+//! ```
+
+use crate::{Service, Token};
+use alloc::boxed::Box;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_util::{stream::FuturesUnordered, Stream, StreamExt};
+
+/// An asynchronous service manager for `!Send` services.
+///
+/// This mirrors [`Meltdown`](crate::Meltdown), but boxes futures without requiring [`Send`], so
+/// it can drive services that hold `Rc`, single-threaded connection handles, or other
+/// thread-local state. As a result, `LocalMeltdown` is itself `!Send` and must be driven on a
+/// single thread, much like [`tokio::task::LocalSet`] drives `!Send` tasks.
+///
+/// [`tokio::task::LocalSet`]: https://docs.rs/tokio/latest/tokio/task/struct.LocalSet.html
+pub struct LocalMeltdown<T> {
+    token: Token,
+    futures: FuturesUnordered<Pin<Box<dyn Future<Output = T> + 'static>>>,
+}
+
+impl<T> LocalMeltdown<T> {
+    /// Creates a new, empty local meltdown instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            token: Token::new(),
+            futures: FuturesUnordered::new(),
+        }
+    }
+
+    /// Returns a reference to the global token.
+    ///
+    /// Triggering this token is equivalent to calling [`LocalMeltdown::trigger`].
+    pub const fn token(&self) -> &Token {
+        &self.token
+    }
+
+    /// Registers a new service.
+    #[must_use]
+    pub fn register<S>(self, service: S) -> Self
+    where
+        S: Service,
+        S::Future: Future<Output = T> + 'static,
+    {
+        self.futures.push(Box::pin(service.run(self.token.clone())));
+        self
+    }
+
+    /// Triggers a meltdown.
+    ///
+    /// This will call [`Token::trigger`] on the tokens passed to each managed service, signalling
+    /// to begin a graceful shutdown.
+    pub fn trigger(&self) {
+        self.token.trigger();
+    }
+
+    /// Returns the result of the next service to shut down.
+    ///
+    /// If there are no more services left, `None` is returned.
+    ///
+    /// Note that this method must be called in order to drive the inner service futures to
+    /// completion.
+    pub async fn next(&mut self) -> Option<T> {
+        StreamExt::next(self).await
+    }
+}
+
+impl<T> Default for LocalMeltdown<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Stream for LocalMeltdown<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.futures).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+
+    #[test]
+    fn can_register_and_run_non_send_services() {
+        pollster::block_on(async {
+            let state = Rc::new(1);
+
+            let mut meltdown = LocalMeltdown::new().register(move |_| {
+                let state = Rc::clone(&state);
+                async move { *state }
+            });
+
+            assert_eq!(meltdown.next().await, Some(1));
+            assert_eq!(meltdown.next().await, None);
+        });
+    }
+
+    #[test]
+    fn can_trigger_local_meltdown() {
+        pollster::block_on(async {
+            let mut meltdown = LocalMeltdown::new()
+                .register(|t: Token| async move {
+                    t.await;
+                    2
+                })
+                .register(|_| async { 1 });
+
+            assert_eq!(meltdown.next().await, Some(1));
+
+            meltdown.trigger();
+
+            assert_eq!(meltdown.next().await, Some(2));
+            assert!(meltdown.next().await.is_none());
+        });
+    }
+}
@@ -0,0 +1,221 @@
+//! A phased, ordered variant of [`Meltdown`].
+//!
+//! # Examples
+//!
+//! ```
+//! # pollster::block_on(async {
+//! use meltdown::PhasedMeltdown;
+//!
+//! let mut meltdown = PhasedMeltdown::new()
+//!     .register_in(0, |token| async move {
+//!         token.await;
+//!         "stopped accepting connections"
+//!     })
+//!     .register_in(1, |token| async move {
+//!         token.await;
+//!         "closed the database"
+//!     });
+//!
+//! meltdown.trigger();
+//!
+//! // Phase 0 always resolves before phase 1 is even triggered.
+//! assert_eq!(meltdown.next().await, Some((0, "stopped accepting connections")));
+//! assert_eq!(meltdown.next().await, Some((1, "closed the database")));
+//! assert_eq!(meltdown.next().await, None);
+//! # })
+//! ```
+
+use crate::{Service, Token};
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_util::{stream::FuturesUnordered, Stream, StreamExt};
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+struct PhaseState<T> {
+    token: Token,
+    futures: FuturesUnordered<BoxFuture<T>>,
+}
+
+/// An asynchronous service manager that shuts services down in ordered phases.
+///
+/// Services are registered into named phases with [`PhasedMeltdown::register_in`]. When
+/// triggered, the earliest phase's token fires first; only once every service in that phase has
+/// resolved does the next phase's token fire, and so on. Results are still yielded incrementally
+/// as each service resolves, tagged with the phase they belong to.
+///
+/// # Examples
+///
+/// ```
+/// # pollster::block_on(async {
+/// use meltdown::PhasedMeltdown;
+///
+/// let mut meltdown = PhasedMeltdown::new()
+///     .register_in("connections", |token| async move {
+///         token.await;
+///     })
+///     .register_in("database", |token| async move {
+///         token.await;
+///     });
+///
+/// meltdown.trigger();
+///
+/// assert_eq!(meltdown.next().await, Some(("connections", ())));
+/// assert_eq!(meltdown.next().await, Some(("database", ())));
+/// # })
+/// ```
+pub struct PhasedMeltdown<P, T> {
+    phases: BTreeMap<P, PhaseState<T>>,
+    triggered: bool,
+}
+
+impl<P: Ord, T> PhasedMeltdown<P, T> {
+    /// Creates a new, empty phased meltdown instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            phases: BTreeMap::new(),
+            triggered: false,
+        }
+    }
+
+    /// Registers a new service into `phase`.
+    ///
+    /// Phases are triggered in ascending order. Services registered into the same phase share
+    /// that phase's token and are triggered together.
+    #[must_use]
+    pub fn register_in<S>(mut self, phase: P, service: S) -> Self
+    where
+        S: Service,
+        S::Future: Future<Output = T> + Send + 'static,
+    {
+        let state = self.phases.entry(phase).or_insert_with(|| PhaseState {
+            token: Token::new(),
+            futures: FuturesUnordered::new(),
+        });
+        state
+            .futures
+            .push(Box::pin(service.run(state.token.clone())));
+        self
+    }
+
+    /// Triggers a phased meltdown, starting with the earliest phase.
+    ///
+    /// Later phases are triggered automatically, as earlier ones finish draining. Services
+    /// drain and are yielded from [`next`](Self::next) as usual whether or not the meltdown
+    /// has been triggered; only the automatic firing of each phase's token is gated on it.
+    pub fn trigger(&mut self) {
+        self.triggered = true;
+
+        if let Some(state) = self.phases.values().next() {
+            state.token.trigger();
+        }
+    }
+
+    /// Returns the result of the next service to shut down, tagged with its phase.
+    ///
+    /// If there are no more services left in any phase, `None` is returned.
+    ///
+    /// Note that this method must be called in order to drive the inner service futures to
+    /// completion and to advance between phases.
+    pub async fn next(&mut self) -> Option<(P, T)>
+    where
+        P: Clone,
+    {
+        StreamExt::next(self).await
+    }
+}
+
+impl<P: Ord, T> Default for PhasedMeltdown<P, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Ord + Clone, T> Stream for PhasedMeltdown<P, T> {
+    type Item = (P, T);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let Some(mut entry) = self.phases.first_entry() else {
+                return Poll::Ready(None);
+            };
+
+            match Pin::new(&mut entry.get_mut().futures).poll_next(cx) {
+                Poll::Ready(Some(output)) => {
+                    let phase = entry.key().clone();
+                    return Poll::Ready(Some((phase, output)));
+                }
+                Poll::Ready(None) => {
+                    entry.remove();
+
+                    if self.triggered {
+                        if let Some(next) = self.phases.values().next() {
+                            next.token.trigger();
+                        }
+                    }
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_resolve_in_order() {
+        pollster::block_on(async {
+            let mut meltdown = PhasedMeltdown::new()
+                .register_in(1, |token: Token| async move {
+                    token.await;
+                    "b"
+                })
+                .register_in(0, |token: Token| async move {
+                    token.await;
+                    "a"
+                });
+
+            meltdown.trigger();
+
+            assert_eq!(meltdown.next().await, Some((0, "a")));
+            assert_eq!(meltdown.next().await, Some((1, "b")));
+            assert_eq!(meltdown.next().await, None);
+        });
+    }
+
+    #[test]
+    fn services_that_dont_wait_resolve_immediately() {
+        pollster::block_on(async {
+            let mut meltdown = PhasedMeltdown::new().register_in(0, |_token| async { "done" });
+
+            assert_eq!(meltdown.next().await, Some((0, "done")));
+        });
+    }
+
+    #[test]
+    fn later_phases_dont_fire_until_triggered() {
+        use futures_util::FutureExt;
+
+        pollster::block_on(async {
+            let mut meltdown = PhasedMeltdown::new()
+                .register_in(0, |_token| async { "done" })
+                .register_in(1, |token: Token| async move {
+                    token.await;
+                    "closed"
+                });
+
+            // Phase 0 drains on its own, without `trigger` ever being called.
+            assert_eq!(meltdown.next().await, Some((0, "done")));
+
+            // Phase 0 emptying out must not cascade into firing phase 1's token.
+            assert!(meltdown.next().now_or_never().is_none());
+        });
+    }
+}
@@ -0,0 +1,313 @@
+//! Service for restarting a service that exits, according to a [`RestartPolicy`].
+//!
+//! # Examples
+//!
+//! ```
+//! # pollster::block_on(async {
+//! use core::time::Duration;
+//! use meltdown::{
+//!     restart::{Restart, Restarting},
+//!     Meltdown,
+//! };
+//! use std::sync::atomic::{AtomicU32, Ordering};
+//!
+//! static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+//!
+//! let service = Restarting::new(
+//!     |_output: &&str| {
+//!         if ATTEMPTS.fetch_add(1, Ordering::SeqCst) < 2 {
+//!             Restart::After(None)
+//!         } else {
+//!             Restart::Stop
+//!         }
+//!     },
+//!     || |_token| async { "exited" },
+//!     |_delay: Duration| async {},
+//! );
+//!
+//! let mut meltdown = Meltdown::new().register(service);
+//!
+//! assert_eq!(meltdown.next().await, Some("exited"));
+//! assert_eq!(ATTEMPTS.load(Ordering::SeqCst), 3);
+//! # })
+//! ```
+
+use crate::{Service, Token};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+use pin_project_lite::pin_project;
+
+/// The decision made by a [`RestartPolicy`] after a service exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Restart {
+    /// Stop supervising; the final output is yielded to the caller.
+    Stop,
+    /// Restart the service, waiting for the given delay beforehand, if any.
+    After(Option<Duration>),
+}
+
+/// Decides whether and how a service should be restarted after it exits.
+pub trait RestartPolicy<O> {
+    /// Called right before each attempt starts running, including the very first one.
+    ///
+    /// The default implementation does nothing; override it to track how long an attempt
+    /// actually stayed up, as [`ExponentialBackoff`] does.
+    fn on_start(&mut self) {}
+
+    /// Called with the output of the most recent run, returning the restart decision.
+    fn on_exit(&mut self, output: &O) -> Restart;
+}
+
+impl<O, F> RestartPolicy<O> for F
+where
+    F: FnMut(&O) -> Restart,
+{
+    fn on_exit(&mut self, output: &O) -> Restart {
+        self(output)
+    }
+}
+
+/// A [`RestartPolicy`] that doubles its delay after every exit, resetting back to the initial
+/// delay once the service has stayed up for at least `reset_after` before exiting again.
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialBackoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    reset_after: Duration,
+    next_delay: Duration,
+    started_at: Option<std::time::Instant>,
+}
+
+impl ExponentialBackoff {
+    /// Creates a new exponential backoff policy.
+    ///
+    /// The delay starts at `initial_delay` and doubles after every exit, capped at `max_delay`.
+    #[must_use]
+    pub const fn new(initial_delay: Duration, max_delay: Duration, reset_after: Duration) -> Self {
+        Self {
+            initial_delay,
+            max_delay,
+            reset_after,
+            next_delay: initial_delay,
+            started_at: None,
+        }
+    }
+}
+
+impl<O> RestartPolicy<O> for ExponentialBackoff {
+    fn on_start(&mut self) {
+        self.started_at = Some(std::time::Instant::now());
+    }
+
+    fn on_exit(&mut self, _output: &O) -> Restart {
+        let now = std::time::Instant::now();
+
+        let stayed_up = self
+            .started_at
+            .is_some_and(|started_at| now.duration_since(started_at) >= self.reset_after);
+
+        if stayed_up {
+            self.next_delay = self.initial_delay;
+        }
+
+        let delay = self.next_delay;
+        self.next_delay = (self.next_delay * 2).min(self.max_delay);
+
+        Restart::After(Some(delay))
+    }
+}
+
+/// Restarts a service, built from a factory, according to a [`RestartPolicy`] when it exits.
+///
+/// Because [`Service::run`] consumes the service, `Restarting` wraps a factory that builds a
+/// fresh instance for every attempt instead of a single instance.
+#[derive(Debug, Clone, Copy)]
+pub struct Restarting<P, F, D> {
+    policy: P,
+    factory: F,
+    delay: D,
+}
+
+impl<P, F, D> Restarting<P, F, D> {
+    /// Creates a new restarting service.
+    ///
+    /// `policy` decides whether to restart after each exit, `factory` builds a fresh service for
+    /// every attempt, and `delay` turns a [`Duration`] into a future that resolves once that much
+    /// time has passed, so the crate stays runtime-agnostic.
+    pub const fn new(policy: P, factory: F, delay: D) -> Self {
+        Self {
+            policy,
+            factory,
+            delay,
+        }
+    }
+}
+
+impl<P, F, S, D, Fut> Service for Restarting<P, F, D>
+where
+    F: FnMut() -> S,
+    S: Service,
+    P: RestartPolicy<<S::Future as Future>::Output>,
+    D: Fn(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Future = RestartingFuture<P, F, D, S::Future, Fut>;
+
+    fn run(self, token: Token) -> Self::Future {
+        let Self {
+            mut policy,
+            mut factory,
+            delay,
+        } = self;
+
+        policy.on_start();
+        let future = factory().run(token.clone());
+
+        RestartingFuture {
+            policy,
+            factory,
+            delay,
+            token,
+            state: State::Running { future },
+        }
+    }
+}
+
+pin_project! {
+    #[project = StateProj]
+    enum State<RunFut, DelayFut> {
+        Running { #[pin] future: RunFut },
+        Delaying { #[pin] future: DelayFut },
+    }
+}
+
+pin_project! {
+    /// Future for the [`Restarting`] service.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct RestartingFuture<P, F, D, RunFut, DelayFut> {
+        policy: P,
+        factory: F,
+        delay: D,
+        token: Token,
+        #[pin]
+        state: State<RunFut, DelayFut>,
+    }
+}
+
+impl<P, F, S, D, Fut> Future for RestartingFuture<P, F, D, S::Future, Fut>
+where
+    F: FnMut() -> S,
+    S: Service,
+    P: RestartPolicy<<S::Future as Future>::Output>,
+    D: Fn(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Output = <S::Future as Future>::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Running { future } => {
+                    let output = ready!(future.poll(cx));
+
+                    match this.policy.on_exit(&output) {
+                        Restart::Stop => return Poll::Ready(output),
+                        Restart::After(None) => {
+                            this.policy.on_start();
+                            let future = (this.factory)().run(this.token.clone());
+                            this.state.set(State::Running { future });
+
+                            // Rebuilding can resolve synchronously, so looping straight back into
+                            // polling it here could spin forever without ever yielding to the
+                            // executor. Wake ourselves and return instead, bounding each `poll`
+                            // call to at most one undelayed restart. Restarts that go through a
+                            // delay loop back here once the delay future resolves.
+                            cx.waker().wake_by_ref();
+                            return Poll::Pending;
+                        }
+                        Restart::After(Some(delay)) => {
+                            let future = (this.delay)(delay);
+                            this.state.set(State::Delaying { future });
+                        }
+                    }
+                }
+                StateProj::Delaying { future } => {
+                    ready!(future.poll(cx));
+
+                    this.policy.on_start();
+                    let future = (this.factory)().run(this.token.clone());
+                    this.state.set(State::Running { future });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_yields_the_final_output() {
+        pollster::block_on(async {
+            let service = Restarting::new(
+                |_output: &&str| Restart::Stop,
+                || |_token| async { "done" },
+                |_delay: Duration| async {},
+            );
+
+            assert_eq!(service.run(Token::new()).await, "done");
+        });
+    }
+
+    #[test]
+    fn restarts_until_policy_stops() {
+        pollster::block_on(async {
+            let mut restarts = 0;
+
+            let service = Restarting::new(
+                move |_output: &u32| {
+                    if restarts < 2 {
+                        restarts += 1;
+                        Restart::After(None)
+                    } else {
+                        Restart::Stop
+                    }
+                },
+                || |_token| async { 1u32 },
+                |_delay: Duration| async {},
+            );
+
+            assert_eq!(service.run(Token::new()).await, 1);
+        });
+    }
+
+    #[test]
+    fn backoff_does_not_reset_while_the_service_keeps_crashing_quickly() {
+        let mut policy = ExponentialBackoff::new(
+            Duration::from_millis(50),
+            Duration::from_secs(1),
+            Duration::from_millis(200),
+        );
+
+        let first = policy.on_exit(&());
+        assert_eq!(first, Restart::After(Some(Duration::from_millis(50))));
+
+        // Each restart is followed almost immediately by another crash, well under
+        // `reset_after` of actual uptime, so the delay must keep growing instead of resetting.
+        let mut last = first;
+        for _ in 0..3 {
+            RestartPolicy::<()>::on_start(&mut policy);
+            std::thread::sleep(Duration::from_millis(10));
+            last = policy.on_exit(&());
+        }
+
+        assert_ne!(last, Restart::After(Some(Duration::from_millis(50))));
+    }
+}
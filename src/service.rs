@@ -15,7 +15,7 @@ pub trait Service {
 impl<F, Fut> Service for F
 where
     F: FnOnce(Token) -> Fut,
-    Fut: Future + Send + 'static,
+    Fut: Future + 'static,
 {
     type Future = Fut;
 
@@ -30,7 +30,7 @@
 //! # })
 //! ```
 
-use crate::Service;
+use crate::{Layer, Service};
 use core::{
     future::Future,
     pin::Pin,
@@ -74,6 +74,27 @@ impl<T, S: Service> Service for Tagged<T, S> {
     }
 }
 
+/// A [`Layer`] that wraps a service with [`Tagged`].
+#[derive(Debug, Clone, Copy)]
+pub struct TaggedLayer<T> {
+    tag: T,
+}
+
+impl<T> TaggedLayer<T> {
+    /// Creates a new tagged layer.
+    pub const fn new(tag: T) -> Self {
+        Self { tag }
+    }
+}
+
+impl<T, S> Layer<S> for TaggedLayer<T> {
+    type Service = Tagged<T, S>;
+
+    fn layer(self, inner: S) -> Self::Service {
+        Tagged::new(self.tag, inner)
+    }
+}
+
 impl<T, F: Future> Future for TaggedFuture<T, F> {
     type Output = (T, F::Output);
 
@@ -91,7 +112,7 @@ impl<T, F: Future> Future for TaggedFuture<T, F> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Token;
+    use crate::{ServiceExt, Token};
 
     #[test]
     fn response_contains_the_tag() {
@@ -100,4 +121,12 @@ mod tests {
             assert_eq!(service.run(Token::new()).await, ("my-tag", "response"));
         });
     }
+
+    #[test]
+    fn tagged_layer_wraps_a_service_with_a_tag() {
+        pollster::block_on(async {
+            let service = (|_token| async { "response" }).layer(TaggedLayer::new("my-tag"));
+            assert_eq!(service.run(Token::new()).await, ("my-tag", "response"));
+        });
+    }
 }